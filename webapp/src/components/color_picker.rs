@@ -0,0 +1,278 @@
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{window, CanvasGradient, CanvasRenderingContext2d, HtmlCanvasElement, HtmlInputElement, MouseEvent};
+use yew::prelude::*;
+
+use crate::components::paint::Color;
+
+const SQUARE_SIZE: f64 = 192.0;
+const HUE_WIDTH: f64 = 24.0;
+
+#[derive(Properties, PartialEq)]
+pub struct ColorPickerProps {
+    pub on_change: Callback<Color>,
+}
+
+pub struct ColorPicker {
+    square_ref: NodeRef,
+    hue_ref: NodeRef,
+    hue: f64,
+    saturation: f64,
+    value: f64,
+    alpha: f64,
+    dragging_square: bool,
+    dragging_hue: bool,
+}
+
+pub enum Msg {
+    SquareDown(MouseEvent),
+    SquareMove(MouseEvent),
+    SquareUp,
+    HueDown(MouseEvent),
+    HueMove(MouseEvent),
+    HueUp,
+    ChangeAlpha(Event),
+}
+
+impl ColorPicker {
+    fn current_color(&self) -> Color {
+        let (r, g, b) = hsv_to_rgb(self.hue, self.saturation, self.value);
+        Color { r, g, b, a: Some(self.alpha) }
+    }
+
+    fn pick_square(&mut self, event: &MouseEvent) {
+        let x = (event.offset_x() as f64).clamp(0.0, SQUARE_SIZE);
+        let y = (event.offset_y() as f64).clamp(0.0, SQUARE_SIZE);
+        self.saturation = x / SQUARE_SIZE;
+        self.value = 1.0 - y / SQUARE_SIZE;
+    }
+
+    fn pick_hue(&mut self, event: &MouseEvent) {
+        let y = (event.offset_y() as f64).clamp(0.0, SQUARE_SIZE);
+        self.hue = (y / SQUARE_SIZE) * 360.0;
+    }
+
+    fn redraw(&self) {
+        if let Some(context) = canvas_context(&self.square_ref) {
+            draw_square(&context, self.hue);
+        }
+        if let Some(context) = canvas_context(&self.hue_ref) {
+            draw_hue_slider(&context);
+        }
+    }
+}
+
+impl Component for ColorPicker {
+    type Message = Msg;
+    type Properties = ColorPickerProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            square_ref: NodeRef::default(),
+            hue_ref: NodeRef::default(),
+            hue: 240.0,
+            saturation: 1.0,
+            value: 1.0,
+            alpha: 1.0,
+            dragging_square: false,
+            dragging_hue: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::SquareDown(event) => {
+                self.dragging_square = true;
+                self.pick_square(&event);
+            }
+            Msg::SquareMove(event) if self.dragging_square => {
+                self.pick_square(&event);
+            }
+            Msg::SquareUp => {
+                self.dragging_square = false;
+                return false;
+            }
+            Msg::HueDown(event) => {
+                self.dragging_hue = true;
+                self.pick_hue(&event);
+            }
+            Msg::HueMove(event) if self.dragging_hue => {
+                self.pick_hue(&event);
+            }
+            Msg::HueUp => {
+                self.dragging_hue = false;
+                return false;
+            }
+            Msg::ChangeAlpha(event) => {
+                if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
+                    self.alpha = input.value().parse::<f64>().unwrap_or(1.0) / 100.0;
+                }
+            }
+            _ => return false,
+        }
+
+        ctx.props().on_change.emit(self.current_color());
+        true
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if _first_render {
+            if let Some(canvas) = self.square_ref.cast::<HtmlCanvasElement>() {
+                canvas.set_width(SQUARE_SIZE as u32);
+                canvas.set_height(SQUARE_SIZE as u32);
+            }
+            if let Some(canvas) = self.hue_ref.cast::<HtmlCanvasElement>() {
+                canvas.set_width(HUE_WIDTH as u32);
+                canvas.set_height(SQUARE_SIZE as u32);
+            }
+
+            // Bound to the document (rather than the square/hue canvases) so a
+            // drag still ends when the button is released outside either one.
+            if let Some(document) = window().and_then(|win| win.document()) {
+                let link = ctx.link().clone();
+                let on_mouseup = Closure::wrap(Box::new(move |_event: MouseEvent| {
+                    link.send_message(Msg::SquareUp);
+                    link.send_message(Msg::HueUp);
+                }) as Box<dyn FnMut(MouseEvent)>);
+                let _ = document.add_event_listener_with_callback("mouseup", on_mouseup.as_ref().unchecked_ref());
+                on_mouseup.forget();
+            }
+        }
+        self.redraw();
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let onmousedown_square = ctx.link().callback(Msg::SquareDown);
+        let onmousemove_square = ctx.link().callback(Msg::SquareMove);
+        let onmouseup_square = ctx.link().callback(|_| Msg::SquareUp);
+        let onmousedown_hue = ctx.link().callback(Msg::HueDown);
+        let onmousemove_hue = ctx.link().callback(Msg::HueMove);
+        let onmouseup_hue = ctx.link().callback(|_| Msg::HueUp);
+        let onchange_alpha = ctx.link().callback(Msg::ChangeAlpha);
+
+        html! {
+            <div id="color-picker" style="display: flex; align-items: flex-start; gap: 8px;">
+                <canvas
+                    ref={self.square_ref.clone()}
+                    onmousedown={onmousedown_square}
+                    onmousemove={onmousemove_square}
+                    onmouseup={onmouseup_square}
+                    style="border:1px solid black;"
+                />
+                <canvas
+                    ref={self.hue_ref.clone()}
+                    onmousedown={onmousedown_hue}
+                    onmousemove={onmousemove_hue}
+                    onmouseup={onmouseup_hue}
+                    style="border:1px solid black;"
+                />
+                <input type="range" min="0" max="100" value="100" onchange={onchange_alpha} />
+            </div>
+        }
+    }
+}
+
+fn canvas_context(node_ref: &NodeRef) -> Option<CanvasRenderingContext2d> {
+    node_ref.cast::<HtmlCanvasElement>().map(|canvas| {
+        canvas
+            .get_context("2d")
+            .unwrap()
+            .unwrap()
+            .dyn_into::<CanvasRenderingContext2d>()
+            .unwrap()
+    })
+}
+
+fn draw_square(context: &CanvasRenderingContext2d, hue: f64) {
+    let (r, g, b) = hsv_to_rgb(hue, 1.0, 1.0);
+    context.set_fill_style_str(&format!("rgb({}, {}, {})", r, g, b));
+    context.fill_rect(0.0, 0.0, SQUARE_SIZE, SQUARE_SIZE);
+
+    let white_gradient = linear_gradient(context, 0.0, 0.0, SQUARE_SIZE, 0.0, &[
+        (0.0, "rgba(255, 255, 255, 1)"),
+        (1.0, "rgba(255, 255, 255, 0)"),
+    ]);
+    context.set_fill_style_canvas_gradient(&white_gradient);
+    context.fill_rect(0.0, 0.0, SQUARE_SIZE, SQUARE_SIZE);
+
+    let black_gradient = linear_gradient(context, 0.0, 0.0, 0.0, SQUARE_SIZE, &[
+        (0.0, "rgba(0, 0, 0, 0)"),
+        (1.0, "rgba(0, 0, 0, 1)"),
+    ]);
+    context.set_fill_style_canvas_gradient(&black_gradient);
+    context.fill_rect(0.0, 0.0, SQUARE_SIZE, SQUARE_SIZE);
+}
+
+fn draw_hue_slider(context: &CanvasRenderingContext2d) {
+    let gradient = linear_gradient(context, 0.0, 0.0, 0.0, SQUARE_SIZE, &[
+        (0.0, "#ff0000"),
+        (1.0 / 6.0, "#ffff00"),
+        (2.0 / 6.0, "#00ff00"),
+        (3.0 / 6.0, "#00ffff"),
+        (4.0 / 6.0, "#0000ff"),
+        (5.0 / 6.0, "#ff00ff"),
+        (1.0, "#ff0000"),
+    ]);
+    context.set_fill_style_canvas_gradient(&gradient);
+    context.fill_rect(0.0, 0.0, HUE_WIDTH, SQUARE_SIZE);
+}
+
+fn linear_gradient(context: &CanvasRenderingContext2d, x0: f64, y0: f64, x1: f64, y1: f64, stops: &[(f64, &str)]) -> CanvasGradient {
+    let gradient = context.create_linear_gradient(x0, y0, x1, y1);
+    for &(offset, color) in stops {
+        gradient.add_color_stop(offset as f32, color).unwrap();
+    }
+    gradient
+}
+
+/// Converts an HSV triple (`h` in degrees, `s`/`v` in `0..=1`) to 8-bit RGB.
+fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (u8, u8, u8) {
+    let c = v * s;
+    let x = c * (1.0 - (((h / 60.0) % 2.0) - 1.0).abs());
+    let m = v - c;
+
+    let (r1, g1, b1) = match h {
+        h if h < 60.0 => (c, x, 0.0),
+        h if h < 120.0 => (x, c, 0.0),
+        h if h < 180.0 => (0.0, c, x),
+        h if h < 240.0 => (0.0, x, c),
+        h if h < 300.0 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    (
+        ((r1 + m) * 255.0).round() as u8,
+        ((g1 + m) * 255.0).round() as u8,
+        ((b1 + m) * 255.0).round() as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_value_red_at_zero_hue() {
+        assert_eq!(hsv_to_rgb(0.0, 1.0, 1.0), (255, 0, 0));
+    }
+
+    #[test]
+    fn full_value_green_at_one_twenty_hue() {
+        assert_eq!(hsv_to_rgb(120.0, 1.0, 1.0), (0, 255, 0));
+    }
+
+    #[test]
+    fn full_value_blue_at_two_forty_hue() {
+        assert_eq!(hsv_to_rgb(240.0, 1.0, 1.0), (0, 0, 255));
+    }
+
+    #[test]
+    fn zero_saturation_is_a_shade_of_gray() {
+        assert_eq!(hsv_to_rgb(240.0, 0.0, 0.5), (128, 128, 128));
+    }
+
+    #[test]
+    fn zero_value_is_always_black() {
+        assert_eq!(hsv_to_rgb(50.0, 1.0, 0.0), (0, 0, 0));
+    }
+}
@@ -1,11 +1,85 @@
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::HtmlElement;
-use web_sys::{console, window, ClipboardItem, CanvasRenderingContext2d, HtmlCanvasElement, HtmlInputElement, MouseEvent};
+use web_sys::{console, window, Blob, ClipboardEvent, ClipboardItem, CanvasRenderingContext2d, FileReader, HtmlCanvasElement, HtmlImageElement, HtmlInputElement, KeyboardEvent, MouseEvent, ProgressEvent, WheelEvent};
+use js_sys::{Array, Object, Reflect};
 use yew::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::color_picker::ColorPicker;
 
 trait AbstractShape {
     fn draw(&self, canvas: &CanvasRenderingContext2d);
+
+    /// Whether the point `(x, y)` lies on this shape; used for topmost-first
+    /// selection. Shapes that can't be picked (e.g. `Stroke`) keep the default.
+    fn hit_test(&self, _x: f64, _y: f64) -> bool {
+        false
+    }
+
+    fn translate(&mut self, _dx: f64, _dy: f64) {}
+
+    /// Serializable form of this shape, used for project save/load and the
+    /// shareable URL hash.
+    fn to_data(&self) -> ShapeData;
+}
+
+/// Serializable mirror of the `AbstractShape` implementations, since trait
+/// objects can't derive `Serialize`/`Deserialize` directly.
+#[derive(Serialize, Deserialize)]
+enum ShapeData {
+    Rect { x1: f64, y1: f64, x2: f64, y2: f64, color: Color },
+    Circle { x: f64, y: f64, r: f64, color: Color },
+    Stroke { points: Vec<(f64, f64)>, width: f64, color: Color },
+    Image { src: String, x: f64, y: f64 },
+    Group(Vec<ShapeData>),
+}
+
+impl ShapeData {
+    fn into_shape(self) -> Box<dyn AbstractShape> {
+        match self {
+            ShapeData::Rect { x1, y1, x2, y2, color } => Box::new(Rect { x1, y1, x2, y2, color }),
+            ShapeData::Circle { x, y, r, color } => Box::new(Circle { x, y, r, color }),
+            ShapeData::Stroke { points, width, color } => Box::new(Stroke { points, width, color }),
+            ShapeData::Image { src, x, y } => {
+                let image = HtmlImageElement::new().unwrap();
+                image.set_src(&src);
+                Box::new(PastedImage { image, src, x, y })
+            }
+            ShapeData::Group(shapes) => Box::new(Group {
+                shapes: shapes.into_iter().map(ShapeData::into_shape).collect(),
+            }),
+        }
+    }
+}
+
+/// Several shapes drawn as one symmetric figure (e.g. a mirrored stroke or a
+/// radially-copied rect), pushed to `drawn_objects` as a single entry so one
+/// `Undo`/`Redo` affects the whole figure rather than one copy at a time.
+struct Group {
+    shapes: Vec<Box<dyn AbstractShape>>,
+}
+
+impl AbstractShape for Group {
+    fn draw(&self, canvas_context: &CanvasRenderingContext2d) {
+        for shape in &self.shapes {
+            shape.draw(canvas_context);
+        }
+    }
+
+    fn hit_test(&self, x: f64, y: f64) -> bool {
+        self.shapes.iter().any(|shape| shape.hit_test(x, y))
+    }
+
+    fn translate(&mut self, dx: f64, dy: f64) {
+        for shape in &mut self.shapes {
+            shape.translate(dx, dy);
+        }
+    }
+
+    fn to_data(&self) -> ShapeData {
+        ShapeData::Group(self.shapes.iter().map(|shape| shape.to_data()).collect())
+    }
 }
 
 struct Rect {
@@ -21,6 +95,23 @@ impl AbstractShape for Rect{
     fn draw(&self, canvas_context: &CanvasRenderingContext2d) {
         draw_rect(canvas_context, self.x1, self.y1, self.x2, self.y2, self.color);
     }
+
+    fn hit_test(&self, x: f64, y: f64) -> bool {
+        let (min_x, max_x) = (self.x1.min(self.x2), self.x1.max(self.x2));
+        let (min_y, max_y) = (self.y1.min(self.y2), self.y1.max(self.y2));
+        x >= min_x && x <= max_x && y >= min_y && y <= max_y
+    }
+
+    fn translate(&mut self, dx: f64, dy: f64) {
+        self.x1 += dx;
+        self.y1 += dy;
+        self.x2 += dx;
+        self.y2 += dy;
+    }
+
+    fn to_data(&self) -> ShapeData {
+        ShapeData::Rect { x1: self.x1, y1: self.y1, x2: self.x2, y2: self.y2, color: self.color }
+    }
 }
 
 
@@ -35,16 +126,91 @@ impl AbstractShape for Circle{
     fn draw(&self, canvas_context: &CanvasRenderingContext2d) {
         draw_at_position(canvas_context, self.x, self.y, self.color);
     }
+
+    fn hit_test(&self, x: f64, y: f64) -> bool {
+        let (dx, dy) = (x - self.x, y - self.y);
+        (dx * dx + dy * dy).sqrt() <= self.r
+    }
+
+    fn translate(&mut self, dx: f64, dy: f64) {
+        self.x += dx;
+        self.y += dy;
+    }
+
+    fn to_data(&self) -> ShapeData {
+        ShapeData::Circle { x: self.x, y: self.y, r: self.r, color: self.color }
+    }
 }
 
 
+struct Stroke {
+    points: Vec<(f64, f64)>,
+    width: f64,
+    color: Color,
+}
 
-#[derive(Debug, Clone, Copy)]
-struct Color {
-    r: u8,
-    g: u8,
-    b: u8,
-    a: Option<f64>,
+impl AbstractShape for Stroke {
+    fn draw(&self, canvas_context: &CanvasRenderingContext2d) {
+        if self.points.len() < 2 {
+            return;
+        }
+
+        canvas_context.begin_path();
+        canvas_context.set_line_width(self.width);
+        canvas_context.set_line_cap("round");
+        canvas_context.set_line_join("round");
+        canvas_context.set_stroke_style_str(&self.color.to_rgb_str());
+
+        let (x0, y0) = self.points[0];
+        canvas_context.move_to(x0, y0);
+        for &(x, y) in &self.points[1..] {
+            canvas_context.line_to(x, y);
+        }
+        canvas_context.stroke();
+    }
+
+    fn to_data(&self) -> ShapeData {
+        ShapeData::Stroke { points: self.points.clone(), width: self.width, color: self.color }
+    }
+}
+
+
+/// An image pasted in from the system clipboard, anchored at its top-left corner.
+struct PastedImage {
+    image: HtmlImageElement,
+    src: String,
+    x: f64,
+    y: f64,
+}
+
+impl AbstractShape for PastedImage {
+    fn draw(&self, canvas_context: &CanvasRenderingContext2d) {
+        let _ = canvas_context.draw_image_with_html_image_element(&self.image, self.x, self.y);
+    }
+
+    fn hit_test(&self, x: f64, y: f64) -> bool {
+        let (width, height) = (self.image.natural_width() as f64, self.image.natural_height() as f64);
+        x >= self.x && x <= self.x + width && y >= self.y && y <= self.y + height
+    }
+
+    fn translate(&mut self, dx: f64, dy: f64) {
+        self.x += dx;
+        self.y += dy;
+    }
+
+    fn to_data(&self) -> ShapeData {
+        ShapeData::Image { src: self.src.clone(), x: self.x, y: self.y }
+    }
+}
+
+
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct Color {
+    pub(crate) r: u8,
+    pub(crate) g: u8,
+    pub(crate) b: u8,
+    pub(crate) a: Option<f64>,
 }
 
 
@@ -55,41 +221,118 @@ impl Color {
             None => format!("rgb({}, {}, {})", self.r, self.g, self.b)
         }
     }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SymmetryMode {
+    None,
+    MirrorX,
+    MirrorY,
+    MirrorXY,
+    Radial(u32),
+}
+
+/// Mirrors or rotates points drawn by a tool around a fixed center so a
+/// single stroke or shape is replicated across the configured axes.
+#[derive(Debug, Clone, Copy)]
+struct Symmetry {
+    center: (f64, f64),
+    mode: SymmetryMode,
+}
 
-   fn from_hex_str(hex: &str) -> Result<Color, &'static str> {
-        // Remove # if present
-        let hex = hex.trim_start_matches('#');
-        
-        // Validate hex string length
-        if hex.len() != 6  && hex.len() != 8{
-            return Err("Invalid hex color format");
+impl Symmetry {
+    fn expand(&self, p: (f64, f64)) -> Vec<(f64, f64)> {
+        let (cx, cy) = self.center;
+        let (x, y) = p;
+        match self.mode {
+            SymmetryMode::None => vec![(x, y)],
+            SymmetryMode::MirrorX => vec![(x, y), (2.0 * cx - x, y)],
+            SymmetryMode::MirrorY => vec![(x, y), (x, 2.0 * cy - y)],
+            SymmetryMode::MirrorXY => vec![
+                (x, y),
+                (2.0 * cx - x, y),
+                (x, 2.0 * cy - y),
+                (2.0 * cx - x, 2.0 * cy - y),
+            ],
+            SymmetryMode::Radial(n) => {
+                let n = n.max(1);
+                let (dx, dy) = (x - cx, y - cy);
+                (0..n)
+                    .map(|k| {
+                        let theta = 2.0 * std::f64::consts::PI * (k as f64) / (n as f64);
+                        let (sin, cos) = theta.sin_cos();
+                        (cx + dx * cos - dy * sin, cy + dx * sin + dy * cos)
+                    })
+                    .collect()
+            }
         }
-        
-        // Parse each color component
-        let r = u8::from_str_radix(&hex[0..2], 16)
-            .map_err(|_| "Invalid red component")?;
-        let g = u8::from_str_radix(&hex[2..4], 16)
-            .map_err(|_| "Invalid green component")?;
-        let b = u8::from_str_radix(&hex[4..6], 16)
-            .map_err(|_| "Invalid blue component")?;
+    }
 
-        let mut alpha = None;
-        if hex.len() == 8 {
-            alpha = u8::from_str_radix(&hex[6..8], 16).ok();
-        } 
+    /// Cycles through the available modes for the toolbar's symmetry button.
+    fn cycle(&mut self) {
+        self.mode = match self.mode {
+            SymmetryMode::None => SymmetryMode::MirrorX,
+            SymmetryMode::MirrorX => SymmetryMode::MirrorY,
+            SymmetryMode::MirrorY => SymmetryMode::MirrorXY,
+            SymmetryMode::MirrorXY => SymmetryMode::Radial(6),
+            SymmetryMode::Radial(_) => SymmetryMode::None,
+        };
+    }
 
-        let a = alpha.map(|x| f64::from(x) /255.0);
+    fn label(&self) -> &'static str {
+        match self.mode {
+            SymmetryMode::None => "Symmetry: Off",
+            SymmetryMode::MirrorX => "Symmetry: Mirror X",
+            SymmetryMode::MirrorY => "Symmetry: Mirror Y",
+            SymmetryMode::MirrorXY => "Symmetry: Mirror X+Y",
+            SymmetryMode::Radial(_) => "Symmetry: Radial",
+        }
+    }
 
-        
-        Ok(Color { r, g, b, a})
+    /// Like `expand`, but for tools that can only render axis-aligned copies
+    /// (e.g. `RectTool`): a `Radial` rotation that isn't a multiple of 90
+    /// degrees would turn a rectangle into an unrelated skewed box if its
+    /// corners were rotated independently and re-boxed, so those copies are
+    /// dropped here instead.
+    fn expand_axis_aligned(&self, p: (f64, f64)) -> Vec<(f64, f64)> {
+        match self.mode {
+            SymmetryMode::Radial(n) => {
+                let n = n.max(1);
+                self.expand(p)
+                    .into_iter()
+                    .enumerate()
+                    .filter(|&(k, _)| (4 * k as u32) % n == 0)
+                    .map(|(_, p)| p)
+                    .collect()
+            }
+            _ => self.expand(p),
+        }
+    }
+}
+
+
+/// The canvas's current zoom/pan, plus whether the alignment grid should be
+/// drawn. Threaded into `DrawingTool` so mouse coordinates can be converted
+/// from screen space into the shapes' own (unscaled, unpanned) space.
+#[derive(Debug, Clone, Copy)]
+struct Viewport {
+    zoom: f64,
+    pan: (f64, f64),
+    grid_visible: bool,
+}
+
+impl Viewport {
+    fn to_world(&self, x: f64, y: f64) -> (f64, f64) {
+        ((x - self.pan.0) / self.zoom, (y - self.pan.1) / self.zoom)
     }
 }
 
 
 trait DrawingTool {
-    fn draw(&mut self, canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent);
-    fn start_draw(&mut self, canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent);
-    fn end_draw(&mut self, canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent);
+    fn draw(&mut self, canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent, symmetry: &Symmetry, viewport: &Viewport);
+    fn start_draw(&mut self, canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent, symmetry: &Symmetry, viewport: &Viewport);
+    fn end_draw(&mut self, canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent, symmetry: &Symmetry, viewport: &Viewport);
     fn change_primary_color(&mut self, color: Color);
     fn change_secondary_color (&mut self, color: Color);
 }
@@ -98,52 +341,80 @@ trait DrawingTool {
 struct BrushTool {
     size: f64,
     color: Color,
+    stroke_points: Vec<Vec<(f64, f64)>>,
 }
 
 
 impl DrawingTool for BrushTool {
-    fn draw(&mut self, canvas_context: &CanvasRenderingContext2d, _tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent){
-        let circle = Circle {x: event.offset_x() as f64, y: event.offset_y() as f64, r: self.size, color: self.color};
-        drawn_objects.push(Box::new(circle));
+    fn draw(&mut self, _canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, _drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent, symmetry: &Symmetry, viewport: &Viewport){
+        let points = symmetry.expand(viewport.to_world(event.offset_x() as f64, event.offset_y() as f64));
+
+        clear_tool_canvas(tooltip_canvas, viewport, 500.0, 500.0);
+        for (stroke, point) in self.stroke_points.iter_mut().zip(points) {
+            stroke.push(point);
+            let preview = Stroke { points: stroke.clone(), width: self.size, color: self.color };
+            preview.draw(tooltip_canvas);
+        }
     }
 
-    fn start_draw(&mut self, canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent) {
-        self.draw(canvas_context, tooltip_canvas, drawn_objects, event);
+    fn start_draw(&mut self, _canvas_context: &CanvasRenderingContext2d, _tooltip_canvas: &CanvasRenderingContext2d, _drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent, symmetry: &Symmetry, viewport: &Viewport) {
+        self.stroke_points = symmetry
+            .expand(viewport.to_world(event.offset_x() as f64, event.offset_y() as f64))
+            .into_iter()
+            .map(|p| vec![p])
+            .collect();
     }
 
     fn change_primary_color(&mut self, color: Color) {
         self.color = color;
     }
 
-    fn end_draw(&mut self, _canvas_context: &CanvasRenderingContext2d, _tooltip_canvas: &CanvasRenderingContext2d, _drawn_objects: &mut Vec<Box<dyn AbstractShape>>, _event: &MouseEvent) {}
+    fn end_draw(&mut self, _canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent, symmetry: &Symmetry, viewport: &Viewport) {
+        let points = symmetry.expand(viewport.to_world(event.offset_x() as f64, event.offset_y() as f64));
+
+        clear_tool_canvas(tooltip_canvas, viewport, 500.0, 500.0);
+        let shapes: Vec<Box<dyn AbstractShape>> = std::mem::take(&mut self.stroke_points)
+            .into_iter()
+            .zip(points)
+            .map(|(mut stroke, point)| {
+                stroke.push(point);
+                Box::new(Stroke { points: stroke, width: self.size, color: self.color }) as Box<dyn AbstractShape>
+            })
+            .collect();
+        push_as_group(drawn_objects, shapes);
+    }
     fn change_secondary_color (&mut self, _color: Color) {}
 }
 
 struct RectTool {
-    x: f64,
-    y: f64,
+    starts: Vec<(f64, f64)>,
     color: Color,
     tooltip_color: Color,
 }
 
 
 impl DrawingTool for RectTool {
-    fn draw(&mut self, canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent){
-        clear_canvas(tooltip_canvas, 500.0, 500.0);
-        draw_rect(tooltip_canvas, self.x, self.y, event.offset_x() as f64, event.offset_y() as f64, self.tooltip_color);
-    }
+    fn draw(&mut self, _canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, _drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent, symmetry: &Symmetry, viewport: &Viewport){
+        let ends = symmetry.expand_axis_aligned(viewport.to_world(event.offset_x() as f64, event.offset_y() as f64));
 
-    fn start_draw(&mut self, canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent) {
-        self.x = event.offset_x() as f64;
-        self.y = event.offset_y() as f64;
+        clear_tool_canvas(tooltip_canvas, viewport, 500.0, 500.0);
+        for (&(x1, y1), (x2, y2)) in self.starts.iter().zip(ends) {
+            draw_rect(tooltip_canvas, x1, y1, x2, y2, self.tooltip_color);
+        }
     }
 
-    fn end_draw(&mut self, canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent) {
+    fn start_draw(&mut self, _canvas_context: &CanvasRenderingContext2d, _tooltip_canvas: &CanvasRenderingContext2d, _drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent, symmetry: &Symmetry, viewport: &Viewport) {
+        self.starts = symmetry.expand_axis_aligned(viewport.to_world(event.offset_x() as f64, event.offset_y() as f64));
+    }
 
-        clear_canvas(tooltip_canvas, 500.0, 500.0);
-        let rect = Rect {x1: self.x, y1: self.y, x2: event.offset_x() as f64, y2: event.offset_y() as f64, color: self.color};
-        drawn_objects.push(Box::new(rect));
+    fn end_draw(&mut self, _canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent, symmetry: &Symmetry, viewport: &Viewport) {
+        let ends = symmetry.expand_axis_aligned(viewport.to_world(event.offset_x() as f64, event.offset_y() as f64));
 
+        clear_tool_canvas(tooltip_canvas, viewport, 500.0, 500.0);
+        let shapes: Vec<Box<dyn AbstractShape>> = self.starts.iter().zip(ends)
+            .map(|(&(x1, y1), (x2, y2))| Box::new(Rect { x1, y1, x2, y2, color: self.color }) as Box<dyn AbstractShape>)
+            .collect();
+        push_as_group(drawn_objects, shapes);
     }
 
 
@@ -155,6 +426,43 @@ impl DrawingTool for RectTool {
 }
 
 
+struct SelectTool {
+    selected: Option<usize>,
+    last_x: f64,
+    last_y: f64,
+}
+
+
+impl DrawingTool for SelectTool {
+    fn draw(&mut self, _canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent, _symmetry: &Symmetry, viewport: &Viewport){
+        let Some(index) = self.selected else { return };
+        let (x, y) = viewport.to_world(event.offset_x() as f64, event.offset_y() as f64);
+        drawn_objects[index].translate(x - self.last_x, y - self.last_y);
+        self.last_x = x;
+        self.last_y = y;
+
+        clear_tool_canvas(tooltip_canvas, viewport, 500.0, 500.0);
+        drawn_objects[index].draw(tooltip_canvas);
+    }
+
+    fn start_draw(&mut self, _canvas_context: &CanvasRenderingContext2d, _tooltip_canvas: &CanvasRenderingContext2d, drawn_objects: &mut Vec<Box<dyn AbstractShape>>, event: &MouseEvent, _symmetry: &Symmetry, viewport: &Viewport) {
+        let (x, y) = viewport.to_world(event.offset_x() as f64, event.offset_y() as f64);
+        self.selected = drawn_objects.iter().enumerate().rev().find(|(_, shape)| shape.hit_test(x, y)).map(|(index, _)| index);
+        self.last_x = x;
+        self.last_y = y;
+    }
+
+    fn end_draw(&mut self, _canvas_context: &CanvasRenderingContext2d, tooltip_canvas: &CanvasRenderingContext2d, _drawn_objects: &mut Vec<Box<dyn AbstractShape>>, _event: &MouseEvent, _symmetry: &Symmetry, viewport: &Viewport) {
+        if self.selected.take().is_some() {
+            clear_tool_canvas(tooltip_canvas, viewport, 500.0, 500.0);
+        }
+    }
+
+    fn change_primary_color(&mut self, _color: Color) {}
+    fn change_secondary_color (&mut self, _color: Color) {}
+}
+
+
 // Canvas state management
 pub struct CanvasState {
     current_tool: Box<dyn DrawingTool>,
@@ -164,14 +472,17 @@ pub struct CanvasState {
     tooltip_color: Color,
     drawn_objects: Vec<Box<dyn AbstractShape>>,
     undo_stack: Vec<Box<dyn AbstractShape>>,
+    symmetry: Symmetry,
+    cursor: (f64, f64),
 }
 
 impl CanvasState {
     fn new() -> Self {
         Self {
-            current_tool: Box::new(BrushTool { 
+            current_tool: Box::new(BrushTool {
                 size: 5.0,
                 color: Color{r: 0, g: 0, b: 255, a: None},
+                stroke_points: vec![],
             }),
             mouse_pressed: false,
             primary_color: Color{r: 0, g: 0, b: 255, a: None},
@@ -179,6 +490,8 @@ impl CanvasState {
             tooltip_color: Color{r: 200, g: 0, b: 255, a: Some(0.5)},
             drawn_objects: vec![],
             undo_stack: vec![],
+            symmetry: Symmetry { center: (250.0, 250.0), mode: SymmetryMode::None },
+            cursor: (0.0, 0.0),
         }
     }
 }
@@ -191,6 +504,9 @@ pub struct CanvasComponent {
     state: CanvasState,
     height: u32,
     width: u32,
+    zoom: f64,
+    pan: (f64, f64),
+    grid_visible: bool,
 }
 
 pub enum Msg {
@@ -199,12 +515,23 @@ pub enum Msg {
     MouseMove(MouseEvent),
     ChangeTool(Box<dyn DrawingTool>),
     SaveImage,
+    SaveProject,
+    LoadProjectSelected(Event),
+    LoadProject(String),
     ClearCanvas,
-    ChangeColor(Event),
+    ChangeColor(Color),
     SelectRectTool,
     SelectBrushTool,
+    SelectSelectTool,
     Undo,
     Redo,
+    CycleSymmetry,
+    Copy,
+    Paste,
+    PasteImage(HtmlImageElement, String, (f64, f64)),
+    Zoom(WheelEvent),
+    Pan(KeyboardEvent),
+    ToggleGrid,
 }
 
 impl Component for CanvasComponent {
@@ -212,12 +539,20 @@ impl Component for CanvasComponent {
     type Properties = ();
 
     fn create(_ctx: &Context<Self>) -> Self {
+        let mut state = CanvasState::new();
+        if let Some(shapes) = window().and_then(|win| win.location().hash().ok()).and_then(|hash| decode_shared_hash(hash.trim_start_matches('#'))) {
+            state.drawn_objects = shapes;
+        }
+
         Self {
             canvas_ref: NodeRef::default(),
             tool_canvas_ref: NodeRef::default(),
-            state: CanvasState::new(),
+            state,
             height: 500,
             width: 500,
+            zoom: 1.0,
+            pan: (0.0, 0.0),
+            grid_visible: false,
         }
     }
 
@@ -241,15 +576,24 @@ impl Component for CanvasComponent {
 
                 match msg {
                     Msg::MouseDown(event) => {
+                        let viewport = Viewport { zoom: self.zoom, pan: self.pan, grid_visible: self.grid_visible };
                         self.state.mouse_pressed = true;
-                        self.state.current_tool.start_draw(&canvas_context, &tool_context, &mut self.state.drawn_objects, &event);
+                        self.state.current_tool.start_draw(&canvas_context, &tool_context, &mut self.state.drawn_objects, &event, &self.state.symmetry, &viewport);
                     }
-                    Msg::MouseMove(event) if self.state.mouse_pressed => {
-                        self.state.current_tool.draw(&canvas_context, &tool_context, &mut self.state.drawn_objects, &event);
+                    Msg::MouseMove(event) => {
+                        self.state.cursor = (event.offset_x() as f64, event.offset_y() as f64);
+                        if self.state.mouse_pressed {
+                            let viewport = Viewport { zoom: self.zoom, pan: self.pan, grid_visible: self.grid_visible };
+                            self.state.current_tool.draw(&canvas_context, &tool_context, &mut self.state.drawn_objects, &event, &self.state.symmetry, &viewport);
+                        } else {
+                            return false;
+                        }
                     }
                     Msg::MouseUp(event) => {
+                        let viewport = Viewport { zoom: self.zoom, pan: self.pan, grid_visible: self.grid_visible };
                         self.state.mouse_pressed = false;
-                        self.state.current_tool.end_draw(&canvas_context, &tool_context, &mut self.state.drawn_objects, &event);
+                        self.state.current_tool.end_draw(&canvas_context, &tool_context, &mut self.state.drawn_objects, &event, &self.state.symmetry, &viewport);
+                        self.sync_share_hash();
                     }
                     Msg::ChangeTool(tool) => {
                         self.state.current_tool = tool;
@@ -263,43 +607,185 @@ impl Component for CanvasComponent {
 
                             let body = window().unwrap().document().unwrap().body().unwrap();
                             body.append_child(&link).unwrap();
-                            if let Ok(html_element) = link.dyn_into::<HtmlElement>() { 
+                            if let Ok(html_element) = link.dyn_into::<HtmlElement>() {
                                 html_element.click();
                                 body.remove_child(&html_element).unwrap(); // Clean up
                             }
                         });
+                        return false;
+                    }
+                    Msg::SaveProject => {
+                        let json = encode_project(&self.state.drawn_objects);
+                        let encoded: String = js_sys::encode_uri_component(&json).into();
+                        let data_url = format!("data:application/json;charset=utf-8,{}", encoded);
+
+                        let link = window().unwrap().document().unwrap().create_element("a").unwrap();
+                        link.set_attribute("href", &data_url).unwrap();
+                        link.set_attribute("download", "scribblai.json").unwrap();
+                        link.set_attribute("style", "display: none").unwrap();
+
+                        let body = window().unwrap().document().unwrap().body().unwrap();
+                        body.append_child(&link).unwrap();
+                        if let Ok(html_element) = link.dyn_into::<HtmlElement>() {
+                            html_element.click();
+                            body.remove_child(&html_element).unwrap();
+                        }
+                        return false;
+                    }
+                    Msg::LoadProjectSelected(event) => {
+                        if let Some(file) = event.target_dyn_into::<HtmlInputElement>().and_then(|input| input.files()).and_then(|files| files.get(0)) {
+                            let reader = FileReader::new().unwrap();
+                            let link = ctx.link().clone();
+                            let reader_handle = reader.clone();
+                            let onloadend = Closure::wrap(Box::new(move |_event: ProgressEvent| {
+                                if let Some(text) = reader_handle.result().ok().and_then(|result| result.as_string()) {
+                                    link.send_message(Msg::LoadProject(text));
+                                }
+                            }) as Box<dyn FnMut(ProgressEvent)>);
+                            reader.set_onloadend(Some(onloadend.as_ref().unchecked_ref()));
+                            onloadend.forget();
+                            let _ = reader.read_as_text(&file);
+                        }
+                        return false;
+                    }
+                    Msg::LoadProject(json) => {
+                        if let Some(shapes) = parse_shapes(&json) {
+                            self.state.drawn_objects = shapes;
+                            self.state.undo_stack.clear();
+                            self.sync_share_hash();
+                        }
                     }
                     Msg::ClearCanvas => {
                         self.state.drawn_objects.clear();
                         self.state.undo_stack.clear();
+                        self.sync_share_hash();
 
                     }
-                    Msg::ChangeColor(event) => {
-                        if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
-                            let color = Color::from_hex_str(&input.value()).expect("ERROR CONVERTING COLOR");
-                            self.state.primary_color = color;
-                            self.state.current_tool.change_primary_color(color);
-                        }
+                    Msg::ChangeColor(color) => {
+                        self.state.primary_color = color;
+                        self.state.current_tool.change_primary_color(color);
                         return false;
                     }
                     Msg::SelectRectTool => {
-                        self.state.current_tool = Box::new(RectTool{ x: 0.0, y:0.0, color: self.state.primary_color, tooltip_color:self.state.tooltip_color});
+                        self.state.current_tool = Box::new(RectTool{ starts: vec![], color: self.state.primary_color, tooltip_color:self.state.tooltip_color});
                         return false
                     }
                     Msg::SelectBrushTool => {
-                        self.state.current_tool = Box::new(BrushTool{size:0.0, color: self.state.primary_color});
+                        self.state.current_tool = Box::new(BrushTool{size: 5.0, color: self.state.primary_color, stroke_points: vec![]});
+                        return false;
+                    }
+                    Msg::SelectSelectTool => {
+                        self.state.current_tool = Box::new(SelectTool{selected: None, last_x: 0.0, last_y: 0.0});
                         return false;
                     }
                     Msg::Undo => {
                         if let Some(obj) = self.state.drawn_objects.pop() {
                             self.state.undo_stack.push(obj);
+                            self.sync_share_hash();
                         }
                     }
                     Msg::Redo => {
                         if let Some(obj) = self.state.undo_stack.pop() {
                             self.state.drawn_objects.push(obj);
+                            self.sync_share_hash();
                         }
                     }
+                    Msg::CycleSymmetry => {
+                        self.state.symmetry.cycle();
+                        return false;
+                    }
+                    Msg::Zoom(event) => {
+                        event.prevent_default();
+                        let factor = if event.delta_y() < 0.0 { 1.1 } else { 1.0 / 1.1 };
+                        self.zoom_at(event.offset_x() as f64, event.offset_y() as f64, factor);
+                        let viewport = Viewport { zoom: self.zoom, pan: self.pan, grid_visible: self.grid_visible };
+                        clear_tool_canvas(&tool_context, &viewport, self.width as f64, self.height as f64);
+                    }
+                    Msg::Pan(event) => {
+                        match event.key().as_str() {
+                            "ArrowLeft" => self.pan.0 += 40.0,
+                            "ArrowRight" => self.pan.0 -= 40.0,
+                            "ArrowUp" => self.pan.1 += 40.0,
+                            "ArrowDown" => self.pan.1 -= 40.0,
+                            _ => return false,
+                        }
+                        event.prevent_default();
+                        let viewport = Viewport { zoom: self.zoom, pan: self.pan, grid_visible: self.grid_visible };
+                        clear_tool_canvas(&tool_context, &viewport, self.width as f64, self.height as f64);
+                    }
+                    Msg::ToggleGrid => {
+                        self.grid_visible = !self.grid_visible;
+                        let viewport = Viewport { zoom: self.zoom, pan: self.pan, grid_visible: self.grid_visible };
+                        clear_tool_canvas(&tool_context, &viewport, self.width as f64, self.height as f64);
+                    }
+                    Msg::Copy => {
+                        let offscreen = window().unwrap().document().unwrap().create_element("canvas").unwrap().dyn_into::<HtmlCanvasElement>().unwrap();
+                        offscreen.set_width(self.width);
+                        offscreen.set_height(self.height);
+                        let offscreen_context = offscreen.get_context("2d").unwrap().unwrap().dyn_into::<CanvasRenderingContext2d>().unwrap();
+                        draw_rect(&offscreen_context, 0.0, 0.0, self.width as f64, self.height as f64, Color { r: 255, g: 255, b: 255, a: None });
+                        for shape in &self.state.drawn_objects {
+                            shape.draw(&offscreen_context);
+                        }
+
+                        let on_blob = Closure::wrap(Box::new(move |blob: Option<Blob>| {
+                            let Some(blob) = blob else { return };
+                            let mime_types = Object::new();
+                            let _ = Reflect::set(&mime_types, &"image/png".into(), &blob);
+                            if let Ok(item) = ClipboardItem::new(&mime_types) {
+                                if let Some(win) = window() {
+                                    let _ = win.navigator().clipboard().write(&Array::of1(&item));
+                                }
+                            }
+                        }) as Box<dyn FnMut(Option<Blob>)>);
+                        let _ = offscreen.to_blob(on_blob.as_ref().unchecked_ref(), "image/png");
+                        on_blob.forget();
+                        return false;
+                    }
+                    Msg::Paste => {
+                        if let Some(win) = window() {
+                            let viewport = Viewport { zoom: self.zoom, pan: self.pan, grid_visible: self.grid_visible };
+                            let cursor = viewport.to_world(self.state.cursor.0, self.state.cursor.1);
+                            let link = ctx.link().clone();
+                            let on_items = Closure::wrap(Box::new(move |items: JsValue| {
+                                let Some(item) = Array::from(&items).get(0).dyn_into::<ClipboardItem>().ok() else { return };
+                                let link = link.clone();
+                                let cursor = cursor;
+                                let on_blob = Closure::wrap(Box::new(move |blob: JsValue| {
+                                    let Some(blob) = blob.dyn_ref::<Blob>().cloned() else { return };
+                                    // Read the blob into a `data:` URL (rather than a blob object-URL)
+                                    // so the pasted image survives reload and travels through the
+                                    // saved project / share-link JSON.
+                                    let reader = FileReader::new().unwrap();
+                                    let reader_handle = reader.clone();
+                                    let link = link.clone();
+                                    let on_loadend = Closure::wrap(Box::new(move |_event: ProgressEvent| {
+                                        let Some(src) = reader_handle.result().ok().and_then(|result| result.as_string()) else { return };
+                                        let image = HtmlImageElement::new().unwrap();
+                                        let (link, src_for_load, image_for_load) = (link.clone(), src.clone(), image.clone());
+                                        let on_load = Closure::wrap(Box::new(move || {
+                                            link.send_message(Msg::PasteImage(image_for_load.clone(), src_for_load.clone(), cursor));
+                                        }) as Box<dyn FnMut()>);
+                                        image.set_onload(Some(on_load.as_ref().unchecked_ref()));
+                                        on_load.forget();
+                                        image.set_src(&src);
+                                    }) as Box<dyn FnMut(ProgressEvent)>);
+                                    reader.set_onloadend(Some(on_loadend.as_ref().unchecked_ref()));
+                                    on_loadend.forget();
+                                    let _ = reader.read_as_data_url(&blob);
+                                }) as Box<dyn FnMut(JsValue)>);
+                                let _ = item.get_type("image/png").then(&on_blob);
+                                on_blob.forget();
+                            }) as Box<dyn FnMut(JsValue)>);
+                            let _ = win.navigator().clipboard().read().then(&on_items);
+                            on_items.forget();
+                        }
+                        return false;
+                    }
+                    Msg::PasteImage(image, src, (x, y)) => {
+                        self.state.drawn_objects.push(Box::new(PastedImage { image, src, x, y }));
+                        self.sync_share_hash();
+                    }
                     _ => {
                         console::log_1(&"not implemented".into());
                         return false;
@@ -307,8 +793,15 @@ impl Component for CanvasComponent {
                 }
 
 
+                let viewport = Viewport { zoom: self.zoom, pan: self.pan, grid_visible: self.grid_visible };
+
+                let _ = canvas_context.save();
+                let _ = canvas_context.set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
                 let color = Color {r: 255, g: 255, b: 255, a: None};
                 draw_rect(&canvas_context, 0.0, 0.0, self.width as f64, self.height as f64, color);
+                let _ = canvas_context.restore();
+
+                let _ = canvas_context.set_transform(viewport.zoom, 0.0, 0.0, viewport.zoom, viewport.pan.0, viewport.pan.1);
                 for shape in &self.state.drawn_objects {
                     shape.draw(&canvas_context);
                 }
@@ -327,7 +820,25 @@ impl Component for CanvasComponent {
 }
 
 
-    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if _first_render {
+            if let Some(document) = window().and_then(|win| win.document()) {
+                let copy_link = ctx.link().clone();
+                let on_copy = Closure::wrap(Box::new(move |_event: ClipboardEvent| {
+                    copy_link.send_message(Msg::Copy);
+                }) as Box<dyn FnMut(ClipboardEvent)>);
+                let _ = document.add_event_listener_with_callback("copy", on_copy.as_ref().unchecked_ref());
+                on_copy.forget();
+
+                let paste_link = ctx.link().clone();
+                let on_paste = Closure::wrap(Box::new(move |_event: ClipboardEvent| {
+                    paste_link.send_message(Msg::Paste);
+                }) as Box<dyn FnMut(ClipboardEvent)>);
+                let _ = document.add_event_listener_with_callback("paste", on_paste.as_ref().unchecked_ref());
+                on_paste.forget();
+            }
+        }
+
         if let Some(canvas) = self.canvas_ref.cast::<HtmlCanvasElement>() {
             if _first_render {
                 canvas.set_width(self.width);
@@ -342,6 +853,14 @@ impl Component for CanvasComponent {
             if _first_render{
                     let color = Color {r: 255, g: 255, b: 255, a: None};
                     draw_rect(&context, 0.0, 0.0, self.width as f64, self.height as f64, color);
+
+                    // Paints whatever `create()` restored from the URL hash, so a
+                    // shared link shows the drawing immediately instead of a blank
+                    // canvas until some unrelated action triggers a redraw.
+                    let _ = context.set_transform(self.zoom, 0.0, 0.0, self.zoom, self.pan.0, self.pan.1);
+                    for shape in &self.state.drawn_objects {
+                        shape.draw(&context);
+                    }
             }
         }
 
@@ -359,16 +878,23 @@ impl Component for CanvasComponent {
         let onmouseup = ctx.link().callback(Msg::MouseUp);
         let onmousemove = ctx.link().callback(Msg::MouseMove);
         let onsaveimage = ctx.link().callback(|_| Msg::SaveImage);
+        let onsaveproject = ctx.link().callback(|_| Msg::SaveProject);
+        let onloadproject = ctx.link().callback(Msg::LoadProjectSelected);
         let onclear = ctx.link().callback(|_| Msg::ClearCanvas);
         let changecolor = ctx.link().callback(Msg::ChangeColor);
         let select_brushtool = ctx.link().callback(|_| Msg::SelectBrushTool);
         let select_recttool = ctx.link().callback(|_| Msg::SelectRectTool);
+        let select_selecttool = ctx.link().callback(|_| Msg::SelectSelectTool);
         let undo = ctx.link().callback(|_| Msg::Undo);
         let redo = ctx.link().callback(|_| Msg::Redo);
+        let cycle_symmetry = ctx.link().callback(|_| Msg::CycleSymmetry);
+        let onwheel = ctx.link().callback(Msg::Zoom);
+        let onkeydown = ctx.link().callback(Msg::Pan);
+        let toggle_grid = ctx.link().callback(|_| Msg::ToggleGrid);
 
         html! {
-            <div>
-                <canvas 
+            <div tabindex="0" {onkeydown}>
+                <canvas
                     ref={self.tool_canvas_ref.clone()}
                     style="border:1px solid transparent; position: absolute; top: 0; left: 0; z-index: 2; pointer-events: none;"
                 />
@@ -378,21 +904,79 @@ impl Component for CanvasComponent {
                     {onmousedown}
                     {onmouseup}
                     {onmousemove}
+                    {onwheel}
                 />
                 <div id="toolbar">
                     <button onclick={select_brushtool}>{"Brush Tool"}</button>
                     <button onclick={select_recttool}>{"Rect Tool"}</button>
+                    <button onclick={select_selecttool}>{"Select Tool"}</button>
                     <button onclick={onclear}>{"Clear"}</button>
                     <button onclick={onsaveimage}>{"Save"}</button>
+                    <button onclick={onsaveproject}>{"Save Project"}</button>
+                    <input type="file" accept="application/json" onchange={onloadproject} />
                     <button onclick={undo}>{"Undo"}</button>
                     <button onclick={redo}>{"Redo"}</button>
-                    <input type="color" onchange={changecolor} />
+                    <button onclick={cycle_symmetry}>{self.state.symmetry.label()}</button>
+                    <button onclick={toggle_grid}>{if self.grid_visible {"Hide Grid"} else {"Show Grid"}}</button>
+                    <ColorPicker on_change={changecolor} />
                 </div>
             </div>
         }
     }
 }
 
+impl CanvasComponent {
+    /// Encodes the current drawing into the URL hash so the page's own URL
+    /// can be shared and will restore the drawing on load.
+    fn sync_share_hash(&self) {
+        let json = encode_project(&self.state.drawn_objects);
+        let encoded: String = js_sys::encode_uri_component(&json).into();
+        if let Some(win) = window() {
+            let _ = win.location().set_hash(&encoded);
+        }
+    }
+
+    /// Multiplies the zoom level by `factor` (clamped to `0.2..=8.0`),
+    /// adjusting `pan` so the world point under `(screen_x, screen_y)` stays
+    /// fixed on screen.
+    fn zoom_at(&mut self, screen_x: f64, screen_y: f64, factor: f64) {
+        let viewport = Viewport { zoom: self.zoom, pan: self.pan, grid_visible: self.grid_visible };
+        let (world_x, world_y) = viewport.to_world(screen_x, screen_y);
+        self.zoom = (self.zoom * factor).clamp(0.2, 8.0);
+        self.pan = (screen_x - world_x * self.zoom, screen_y - world_y * self.zoom);
+    }
+}
+
+/// Pushes `shapes` onto `drawn_objects` as a single undoable unit: one shape
+/// directly, or several (e.g. from a symmetric draw) wrapped in a `Group` so
+/// one `Undo` removes the whole figure instead of one copy at a time.
+fn push_as_group(drawn_objects: &mut Vec<Box<dyn AbstractShape>>, mut shapes: Vec<Box<dyn AbstractShape>>) {
+    match shapes.len() {
+        0 => {}
+        1 => drawn_objects.push(shapes.pop().unwrap()),
+        _ => drawn_objects.push(Box::new(Group { shapes })),
+    }
+}
+
+fn encode_project(drawn_objects: &[Box<dyn AbstractShape>]) -> String {
+    let data: Vec<ShapeData> = drawn_objects.iter().map(|shape| shape.to_data()).collect();
+    serde_json::to_string(&data).unwrap_or_default()
+}
+
+fn parse_shapes(json: &str) -> Option<Vec<Box<dyn AbstractShape>>> {
+    serde_json::from_str::<Vec<ShapeData>>(json)
+        .ok()
+        .map(|shapes| shapes.into_iter().map(ShapeData::into_shape).collect())
+}
+
+fn decode_shared_hash(encoded: &str) -> Option<Vec<Box<dyn AbstractShape>>> {
+    if encoded.is_empty() {
+        return None;
+    }
+    let json: String = js_sys::decode_uri_component(encoded).ok()?.into();
+    parse_shapes(&json)
+}
+
 /// Draws a small circle at the given position on the canvas
 fn draw_at_position(context: &CanvasRenderingContext2d, x: f64, y: f64, color: Color) {
     context.begin_path();
@@ -410,3 +994,145 @@ fn draw_rect(context: &CanvasRenderingContext2d, x1: f64, y1: f64, x2: f64, y2:
 fn clear_canvas(context: &CanvasRenderingContext2d, canvas_width: f64, canvas_height: f64){
     context.clear_rect(0.0, 0.0, canvas_width, canvas_height);
 }
+
+/// Clears the tooltip canvas, then re-applies the viewport's zoom/pan so any
+/// preview a tool draws afterwards lines up with the main canvas, redrawing
+/// the alignment grid on top if it's enabled.
+fn clear_tool_canvas(context: &CanvasRenderingContext2d, viewport: &Viewport, width: f64, height: f64) {
+    let _ = context.save();
+    let _ = context.set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    clear_canvas(context, width, height);
+    let _ = context.restore();
+
+    let _ = context.set_transform(viewport.zoom, 0.0, 0.0, viewport.zoom, viewport.pan.0, viewport.pan.1);
+    if viewport.grid_visible {
+        draw_grid(context, viewport, width, height);
+    }
+}
+
+/// Spacing, in world units, between grid lines.
+const GRID_SPACING: f64 = 25.0;
+/// Below this zoom level the grid would be denser than useful, so it's hidden.
+const GRID_MIN_ZOOM: f64 = 1.5;
+
+/// Strokes a light grid over the visible viewport, spaced every
+/// `GRID_SPACING` world units, to help align shapes while zoomed in.
+fn draw_grid(context: &CanvasRenderingContext2d, viewport: &Viewport, width: f64, height: f64) {
+    if viewport.zoom < GRID_MIN_ZOOM {
+        return;
+    }
+
+    let (min_x, min_y) = viewport.to_world(0.0, 0.0);
+    let (max_x, max_y) = viewport.to_world(width, height);
+
+    context.set_stroke_style_str("rgba(0, 0, 0, 0.15)");
+    context.set_line_width(1.0 / viewport.zoom);
+
+    let mut x = (min_x / GRID_SPACING).floor() * GRID_SPACING;
+    while x <= max_x {
+        context.begin_path();
+        context.move_to(x, min_y);
+        context.line_to(x, max_y);
+        context.stroke();
+        x += GRID_SPACING;
+    }
+
+    let mut y = (min_y / GRID_SPACING).floor() * GRID_SPACING;
+    while y <= max_y {
+        context.begin_path();
+        context.move_to(min_x, y);
+        context.line_to(max_x, y);
+        context.stroke();
+        y += GRID_SPACING;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirror_x_reflects_across_the_center() {
+        let symmetry = Symmetry { center: (100.0, 100.0), mode: SymmetryMode::MirrorX };
+        assert_eq!(symmetry.expand((120.0, 50.0)), vec![(120.0, 50.0), (80.0, 50.0)]);
+    }
+
+    #[test]
+    fn mirror_xy_produces_all_four_quadrant_copies() {
+        let symmetry = Symmetry { center: (0.0, 0.0), mode: SymmetryMode::MirrorXY };
+        let points = symmetry.expand((10.0, 5.0));
+        assert_eq!(points, vec![(10.0, 5.0), (-10.0, 5.0), (10.0, -5.0), (-10.0, -5.0)]);
+    }
+
+    #[test]
+    fn radial_six_fold_divides_a_full_turn_evenly() {
+        let symmetry = Symmetry { center: (0.0, 0.0), mode: SymmetryMode::Radial(6) };
+        let points = symmetry.expand((10.0, 0.0));
+        assert_eq!(points.len(), 6);
+        assert!((points[0].0 - 10.0).abs() < 1e-9 && points[0].1.abs() < 1e-9);
+        // The 180 degree copy lands on the opposite side of the center.
+        assert!((points[3].0 + 10.0).abs() < 1e-9 && points[3].1.abs() < 1e-9);
+    }
+
+    #[test]
+    fn rect_tool_drops_non_cardinal_radial_copies() {
+        let symmetry = Symmetry { center: (0.0, 0.0), mode: SymmetryMode::Radial(6) };
+        // Only the 0 and 180 degree copies are multiples of 90 degrees; the
+        // rest would render as a skewed, not rotated, axis-aligned box.
+        assert_eq!(symmetry.expand_axis_aligned((10.0, 0.0)).len(), 2);
+    }
+
+    #[test]
+    fn rect_tool_keeps_every_copy_for_a_cardinal_radial_mode() {
+        let symmetry = Symmetry { center: (0.0, 0.0), mode: SymmetryMode::Radial(4) };
+        assert_eq!(symmetry.expand_axis_aligned((10.0, 0.0)).len(), 4);
+    }
+
+    fn test_color() -> Color {
+        Color { r: 0, g: 0, b: 0, a: None }
+    }
+
+    #[test]
+    fn rect_hit_test_accepts_either_corner_order() {
+        let rect = Rect { x1: 10.0, y1: 10.0, x2: 0.0, y2: 0.0, color: test_color() };
+        assert!(rect.hit_test(5.0, 5.0));
+        assert!(!rect.hit_test(15.0, 5.0));
+    }
+
+    #[test]
+    fn rect_hit_test_includes_its_boundary() {
+        let rect = Rect { x1: 0.0, y1: 0.0, x2: 10.0, y2: 10.0, color: test_color() };
+        assert!(rect.hit_test(0.0, 0.0));
+        assert!(rect.hit_test(10.0, 10.0));
+    }
+
+    #[test]
+    fn circle_hit_test_uses_distance_from_center() {
+        let circle = Circle { x: 0.0, y: 0.0, r: 5.0, color: test_color() };
+        assert!(circle.hit_test(3.0, 4.0));
+        assert!(!circle.hit_test(3.0, 4.1));
+    }
+
+    #[test]
+    fn encode_then_parse_round_trips_shapes() {
+        let color = test_color();
+        let drawn_objects: Vec<Box<dyn AbstractShape>> = vec![
+            Box::new(Rect { x1: 0.0, y1: 0.0, x2: 10.0, y2: 10.0, color }),
+            Box::new(Circle { x: 5.0, y: 5.0, r: 2.0, color }),
+            Box::new(Stroke { points: vec![(0.0, 0.0), (1.0, 1.0)], width: 3.0, color }),
+        ];
+
+        let encoded = encode_project(&drawn_objects);
+        let parsed = parse_shapes(&encoded).expect("valid JSON should parse back into shapes");
+
+        // `ShapeData` has no `PartialEq`, so round-tripping the parsed shapes
+        // back through `encode_project` and comparing JSON is the simplest way
+        // to confirm nothing was lost or reordered.
+        assert_eq!(encode_project(&parsed), encoded);
+    }
+
+    #[test]
+    fn parse_shapes_rejects_invalid_json() {
+        assert!(parse_shapes("not json").is_none());
+    }
+}